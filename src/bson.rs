@@ -0,0 +1,4 @@
+//! Re-exports of the BSON types and macros used throughout this crate's public API, so that
+//! consumers do not need to depend on the `bson` crate directly.
+
+pub use bson::{bson, doc, oid::ObjectId, Bson, Document};
@@ -0,0 +1,27 @@
+//! Internal helpers for pulling well-known fields out of raw BSON command replies.
+
+use crate::bson::Document;
+use crate::error::TopologyVersion;
+
+/// Extracts the `errorLabels` array from a command reply, if present.
+pub(crate) fn get_error_labels(reply: &Document) -> Vec<String> {
+    reply
+        .get_array("errorLabels")
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| label.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Extracts the `topologyVersion` field from a command reply, if present and well-formed.
+pub(crate) fn get_topology_version(reply: &Document) -> Option<TopologyVersion> {
+    let topology_version = reply.get_document("topologyVersion").ok()?;
+
+    Some(TopologyVersion {
+        process_id: topology_version.get_object_id("processId").ok()?.clone(),
+        counter: topology_version.get_i64("counter").ok()?,
+    })
+}
@@ -0,0 +1,207 @@
+//! Defines the `Client` type, the entry point for talking to a MongoDB deployment, and the
+//! command-executing path that every operation runs through.
+
+mod session;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::cmap::ConnectionPool;
+use crate::db::Database;
+use crate::error::{ErrorKind, Result, NO_WRITES_PERFORMED};
+use crate::operation::{Operation, Retryability};
+use crate::options::{ClientOptions, StreamAddress};
+use crate::sdam::Topology;
+
+use session::Session;
+
+/// The client is the entry point for interacting with a MongoDB deployment, and the only part of
+/// this crate's API that talks to the network. It is safe to share across threads: `clone()` is
+/// cheap, and every clone refers to the same underlying connections and topology state.
+#[derive(Clone)]
+pub struct Client {
+    pub(crate) options: Arc<ClientOptions>,
+    topology: Topology,
+    pools: Arc<Mutex<HashMap<StreamAddress, Arc<ConnectionPool>>>>,
+    session: Arc<Session>,
+}
+
+impl Client {
+    /// Creates a new `Client` from a connection string, e.g. `"mongodb://localhost:27017"`.
+    pub fn with_uri_str(uri: impl AsRef<str>) -> Result<Self> {
+        let _ = uri;
+        Err(ErrorKind::ArgumentError {
+            message: "parsing connection strings is not yet supported; use \
+                      Client::with_options instead"
+                .to_string(),
+        }
+        .into())
+    }
+
+    /// Creates a new `Client` from a fully-specified set of `ClientOptions`.
+    pub fn with_options(options: ClientOptions) -> Result<Self> {
+        let topology = Topology::new(&options);
+
+        Ok(Client {
+            options: Arc::new(options),
+            topology,
+            pools: Arc::new(Mutex::new(HashMap::new())),
+            session: Arc::new(Session::new()),
+        })
+    }
+
+    /// Gets a handle to the database with the given name.
+    pub fn database(&self, name: &str) -> Database {
+        Database::new(self.clone(), name.to_string())
+    }
+
+    /// Runs a raw command against `db` on a selected server, with no retry behavior. Used by
+    /// `Database::run_command`, since an arbitrary command's idempotency cannot be assumed.
+    pub(crate) fn run_raw_command(
+        &self,
+        db: &str,
+        command: crate::bson::Document,
+    ) -> Result<crate::bson::Document> {
+        let address = self
+            .topology
+            .select_server(&crate::selection_criteria::SelectionCriteria::default())?;
+        let pool = self.pool_for(&address);
+        let mut connection = pool.check_out()?;
+        let result = connection.execute(db, command);
+        pool.check_in(connection);
+        result
+    }
+
+    fn pool_for(&self, address: &StreamAddress) -> Arc<ConnectionPool> {
+        let mut pools = self.pools.lock().unwrap();
+        if let Some(pool) = pools.get(address) {
+            return pool.clone();
+        }
+
+        let pool = Arc::new(ConnectionPool::new(
+            address.clone(),
+            self.options.cmap_event_handler.clone(),
+            self.options.min_pool_size.unwrap_or(0),
+            self.options.max_idle_time,
+        ));
+        ConnectionPool::start_background_thread(&pool);
+
+        pools.insert(address.clone(), pool.clone());
+        pool
+    }
+
+    /// Runs a single attempt of `operation` against the server at `address`, without any server
+    /// selection or retry behavior.
+    ///
+    /// Every command carries this client's session `lsid`; if `txn_number` is `Some`, it is
+    /// attached too. `execute_operation` passes the same `txn_number` for both the original
+    /// attempt and its retry, which is what lets the server recognize a retried write as a
+    /// duplicate of the one it is retrying rather than a second write.
+    fn execute_operation_attempt<T: Operation>(
+        &self,
+        operation: &mut T,
+        address: &StreamAddress,
+        txn_number: Option<i64>,
+    ) -> Result<T::O> {
+        let pool = self.pool_for(address);
+        let mut connection = pool.check_out()?;
+
+        let mut command = operation.build()?;
+        command.insert("lsid", self.session.lsid());
+        if let Some(txn_number) = txn_number {
+            command.insert("txnNumber", txn_number);
+        }
+
+        let result = connection.execute(operation.db(), command);
+
+        match result {
+            Ok(response) => {
+                pool.check_in(connection);
+                operation.handle_response(response, address)
+            }
+            Err(e) => {
+                pool.check_in(connection);
+                if self.topology.handle_error(address, &e) {
+                    pool.clear();
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Runs a single attempt of `operation` against a specific, already-known server, with no
+    /// server selection and no retry behavior. Used by `Cursor::get_more`, whose `getMore` must
+    /// go to the exact server that opened the cursor, not wherever selection criteria would
+    /// otherwise pick.
+    pub(crate) fn execute_operation_on<T: Operation>(
+        &self,
+        operation: &mut T,
+        address: &StreamAddress,
+    ) -> Result<T::O> {
+        self.execute_operation_attempt(operation, address, None)
+    }
+
+    /// Executes `operation`, retrying it once against a freshly-selected server if it is
+    /// retryable and its first attempt fails with a retryable error.
+    pub(crate) fn execute_operation<T: Operation>(&self, operation: &mut T) -> Result<T::O> {
+        let address = self.topology.select_server(operation.selection_criteria())?;
+
+        // A retryable write's txnNumber is chosen once, up front, and reused for both the
+        // original attempt and its retry below; that is what lets the server tell the retry
+        // apart from a second, independent write. When retryable writes are disabled, txnNumber
+        // must be withheld: sending it would make the server treat the write as retryable (and
+        // dedup by statement id) even though the user opted out. lsid is still attached as
+        // usual, since an lsid without a txnNumber isn't treated as a retryable write.
+        let txn_number = match operation.retryability() {
+            Retryability::Write if self.options.retry_writes_enabled() => {
+                Some(self.session.next_txn_number())
+            }
+            _ => None,
+        };
+
+        let first_attempt = self.execute_operation_attempt(operation, &address, txn_number);
+
+        let first_error = match first_attempt {
+            Ok(output) => return Ok(output),
+            Err(e) => e,
+        };
+
+        let retrying_enabled = match operation.retryability() {
+            Retryability::None => false,
+            Retryability::Read => self.options.retry_reads_enabled(),
+            Retryability::Write => self.options.retry_writes_enabled(),
+        };
+
+        if !retrying_enabled || !first_error.is_retryable() {
+            return Err(first_error);
+        }
+
+        // Writes retry against a different writable server, since the one that failed may no
+        // longer be primary. Reads retry against whichever server best matches the operation's
+        // selection criteria, which may legitimately be the same server again.
+        let retry_address = match operation.retryability() {
+            Retryability::Write => self
+                .topology
+                .select_different_writable_server(&address)
+                .unwrap_or_else(|_| address.clone()),
+            _ => self
+                .topology
+                .select_server(operation.selection_criteria())
+                .unwrap_or_else(|_| address.clone()),
+        };
+
+        match self.execute_operation_attempt(operation, &retry_address, txn_number) {
+            Ok(output) => Ok(output),
+            // If the retry itself fails with a command error carrying NoWritesPerformed, the
+            // server is telling us the retry never actually ran the write; that tells us nothing
+            // beyond what the original error already did, so surface the original error instead
+            // of masking it with this one.
+            Err(retry_error) if matches!(retry_error.as_ref(), ErrorKind::CommandError(_))
+                && retry_error.has_error_label(NO_WRITES_PERFORMED) =>
+            {
+                Err(first_error)
+            }
+            Err(retry_error) => Err(retry_error),
+        }
+    }
+}
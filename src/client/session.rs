@@ -0,0 +1,47 @@
+//! Defines `Session`, the driver-internal bookkeeping that lets a retried write be recognized
+//! by the server as a duplicate of the write it is retrying, rather than a second write.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use crate::bson::{doc, Document, ObjectId};
+
+/// An implicit session, owned by a `Client` and shared by every operation it runs.
+///
+/// Every retryable write command carries this session's `lsid` plus a `txnNumber` that
+/// increases by one for each new logical write; the server uses the pair to tell a retried
+/// write apart from a second one. This driver does not yet expose an explicit session API, so a
+/// `Client` only ever has the one implicit session, shared across all of its clones.
+pub(crate) struct Session {
+    id: Document,
+    txn_number: AtomicI64,
+}
+
+impl Session {
+    pub(crate) fn new() -> Self {
+        Session {
+            // A real `lsid` is a UUID; this driver has no UUID dependency, so it uses an
+            // `ObjectId` instead, which is unique for the same reason: a timestamp, a
+            // per-process identifier, and a counter. The server only needs it to be unique per
+            // session, not to be a UUID specifically.
+            id: doc! {
+                "id": ObjectId::new()
+                    .expect("failed to generate a session id")
+            },
+            txn_number: AtomicI64::new(0),
+        }
+    }
+
+    /// Returns this session's `lsid` document, to be attached to every retryable write command.
+    pub(crate) fn lsid(&self) -> Document {
+        self.id.clone()
+    }
+
+    /// Returns the next `txnNumber` for a new logical write, advancing the counter.
+    ///
+    /// The same number must then be reused for every attempt (the original and its retry) of
+    /// that one logical write, since it is what tells the server the retry is not a second
+    /// write.
+    pub(crate) fn next_txn_number(&self) -> i64 {
+        self.txn_number.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
@@ -0,0 +1,341 @@
+//! The Connection Monitoring and Pooling (CMAP) subsystem: owns the TCP connections to a single
+//! server and hands them out to operations that need to talk to that server.
+
+use std::convert::TryInto;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::bson::Document;
+use crate::error::{Error, ErrorKind, Result};
+use crate::event::{
+    CmapEventHandler, ConnectionCheckOutFailedEvent, ConnectionCheckOutFailedReason,
+    ConnectionCheckOutStartedEvent, ConnectionCheckedInEvent, ConnectionCheckedOutEvent,
+    ConnectionClosedEvent, ConnectionClosedReason, ConnectionCreatedEvent, ConnectionReadyEvent,
+    PoolClearedEvent, PoolCreatedEvent,
+};
+use crate::options::StreamAddress;
+use crate::util;
+
+const OP_MSG: i32 = 2013;
+
+/// How often a pool's background thread wakes up to top up `min_pool_size` and evict
+/// connections that have been idle for longer than `max_idle_time`.
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A single connection to a server, capable of sending a command and reading back its reply
+/// using the `OP_MSG` wire protocol message.
+pub(crate) struct Connection {
+    stream: TcpStream,
+    request_id: i32,
+
+    /// A driver-generated identifier, unique to this connection within its pool. Used only to
+    /// correlate CMAP events for the same connection.
+    id: u32,
+
+    /// The pool generation this connection was created under. Used by the pool to discard
+    /// connections that predate a clear rather than reuse them.
+    pub(crate) generation: u64,
+
+    /// When this connection was last checked in to its pool. Used by the pool's background
+    /// maintenance to evict connections that have been idle for longer than `max_idle_time`.
+    last_used_at: Instant,
+}
+
+impl Connection {
+    fn connect(address: &StreamAddress, id: u32) -> Result<Self> {
+        let stream = TcpStream::connect((address.hostname.as_str(), address.port.unwrap_or(27017)))
+            .map_err(Error::from_io_error)?;
+
+        Ok(Connection {
+            stream,
+            request_id: 0,
+            id,
+            generation: 0,
+            last_used_at: Instant::now(),
+        })
+    }
+
+    /// Sends `command` against `db` and returns the server's reply document, or the `Error` built
+    /// from it if the server reported a command failure.
+    pub(crate) fn execute(&mut self, db: &str, mut command: Document) -> Result<Document> {
+        command.insert("$db", db);
+
+        self.request_id = self.request_id.wrapping_add(1);
+
+        let mut body = Vec::new();
+        bson::encode_document(&mut body, &command)
+            .map_err(|e| ErrorKind::BsonError(e.to_string()))?;
+
+        let mut message = Vec::with_capacity(16 + 4 + 1 + body.len());
+        message.extend_from_slice(&0i32.to_le_bytes()); // messageLength placeholder
+        message.extend_from_slice(&self.request_id.to_le_bytes());
+        message.extend_from_slice(&0i32.to_le_bytes()); // responseTo
+        message.extend_from_slice(&OP_MSG.to_le_bytes());
+        message.extend_from_slice(&0u32.to_le_bytes()); // flagBits
+        message.push(0); // section kind 0: a single BSON document
+        message.extend_from_slice(&body);
+
+        let len = message.len() as i32;
+        message[0..4].copy_from_slice(&len.to_le_bytes());
+
+        self.stream.write_all(&message).map_err(Error::from_io_error)?;
+
+        let reply = self.read_reply()?;
+
+        match Error::from_command_reply(&reply) {
+            Some(error) => Err(error),
+            None => Ok(reply),
+        }
+    }
+
+    fn read_reply(&mut self) -> Result<Document> {
+        let mut header = [0u8; 16];
+        self.stream
+            .read_exact(&mut header)
+            .map_err(Error::from_io_error)?;
+
+        let message_length = i32::from_le_bytes(header[0..4].try_into().unwrap());
+
+        let mut rest = vec![0u8; (message_length as usize).saturating_sub(16)];
+        self.stream
+            .read_exact(&mut rest)
+            .map_err(Error::from_io_error)?;
+
+        // Skip flagBits (4 bytes) and the section kind byte (1 byte) to reach the reply document.
+        let mut body = &rest[5..];
+        bson::decode_document(&mut body).map_err(|e| ErrorKind::BsonError(e.to_string()).into())
+    }
+}
+
+/// Maintains a set of open `Connection`s to a single server.
+pub(crate) struct ConnectionPool {
+    address: StreamAddress,
+    connections: Mutex<Vec<Connection>>,
+    generation: AtomicU64,
+    next_connection_id: AtomicU32,
+    event_handler: Option<Arc<dyn CmapEventHandler>>,
+    min_pool_size: u32,
+    max_idle_time: Option<Duration>,
+}
+
+impl ConnectionPool {
+    pub(crate) fn new(
+        address: StreamAddress,
+        event_handler: Option<Arc<dyn CmapEventHandler>>,
+        min_pool_size: u32,
+        max_idle_time: Option<Duration>,
+    ) -> Self {
+        if let Some(handler) = &event_handler {
+            handler.handle_pool_created_event(PoolCreatedEvent {
+                address: address.clone(),
+            });
+        }
+
+        ConnectionPool {
+            address,
+            connections: Mutex::new(Vec::new()),
+            generation: AtomicU64::new(0),
+            next_connection_id: AtomicU32::new(0),
+            event_handler,
+            min_pool_size,
+            max_idle_time,
+        }
+    }
+
+    /// Spawns the background thread that periodically tops up `min_pool_size` and evicts
+    /// connections that have been idle for longer than `max_idle_time`. The thread holds only a
+    /// `Weak` reference to `pool`, so it exits on its own once the pool is dropped.
+    pub(crate) fn start_background_thread(pool: &Arc<Self>) {
+        let pool = Arc::downgrade(pool);
+        thread::spawn(move || loop {
+            match Weak::upgrade(&pool) {
+                Some(pool) => pool.run_maintenance(),
+                None => return,
+            }
+            thread::sleep(MAINTENANCE_INTERVAL);
+        });
+    }
+
+    fn run_maintenance(&self) {
+        self.evict_idle_connections();
+        self.ensure_min_pool_size();
+    }
+
+    fn evict_idle_connections(&self) {
+        let max_idle_time = match self.max_idle_time {
+            Some(max_idle_time) => max_idle_time,
+            None => return,
+        };
+
+        let current_generation = self.generation.load(Ordering::SeqCst);
+        let mut connections = self.connections.lock().unwrap();
+        let (keep, evict): (Vec<_>, Vec<_>) = connections.drain(..).partition(|connection| {
+            connection.generation == current_generation
+                && util::duration_since(connection.last_used_at) < max_idle_time
+        });
+        *connections = keep;
+        drop(connections);
+
+        for connection in evict {
+            self.emit(|handler| {
+                handler.handle_connection_closed_event(ConnectionClosedEvent {
+                    address: self.address.clone(),
+                    connection_id: connection.id,
+                    reason: ConnectionClosedReason::Idle,
+                })
+            });
+        }
+    }
+
+    fn ensure_min_pool_size(&self) {
+        let current_generation = self.generation.load(Ordering::SeqCst);
+
+        loop {
+            if self.connections.lock().unwrap().len() >= self.min_pool_size as usize {
+                return;
+            }
+
+            let id = self.next_connection_id.fetch_add(1, Ordering::SeqCst);
+            let mut connection = match Connection::connect(&self.address, id) {
+                Ok(connection) => connection,
+                // Leave the pool below min_pool_size for now; the next maintenance pass will
+                // retry.
+                Err(_) => return,
+            };
+            connection.generation = current_generation;
+
+            self.emit(|handler| {
+                handler.handle_connection_created_event(ConnectionCreatedEvent {
+                    address: self.address.clone(),
+                    connection_id: connection.id,
+                })
+            });
+            self.emit(|handler| {
+                handler.handle_connection_ready_event(ConnectionReadyEvent {
+                    address: self.address.clone(),
+                    connection_id: connection.id,
+                })
+            });
+
+            self.connections.lock().unwrap().push(connection);
+        }
+    }
+
+    fn emit(&self, f: impl FnOnce(&dyn CmapEventHandler)) {
+        if let Some(handler) = &self.event_handler {
+            f(handler.as_ref());
+        }
+    }
+
+    /// Checks out a connection, reusing an idle one from the pool if one is available and still
+    /// current, or establishing a new one otherwise.
+    pub(crate) fn check_out(&self) -> Result<Connection> {
+        self.emit(|handler| {
+            handler.handle_connection_checkout_started_event(ConnectionCheckOutStartedEvent {
+                address: self.address.clone(),
+            })
+        });
+
+        let current_generation = self.generation.load(Ordering::SeqCst);
+
+        let mut connections = self.connections.lock().unwrap();
+        while let Some(connection) = connections.pop() {
+            if connection.generation == current_generation {
+                drop(connections);
+                self.emit(|handler| {
+                    handler.handle_connection_checked_out_event(ConnectionCheckedOutEvent {
+                        address: self.address.clone(),
+                        connection_id: connection.id,
+                    })
+                });
+                return Ok(connection);
+            }
+
+            self.emit(|handler| {
+                handler.handle_connection_closed_event(ConnectionClosedEvent {
+                    address: self.address.clone(),
+                    connection_id: connection.id,
+                    reason: ConnectionClosedReason::Stale,
+                })
+            });
+        }
+        drop(connections);
+
+        let id = self.next_connection_id.fetch_add(1, Ordering::SeqCst);
+        let mut connection = match Connection::connect(&self.address, id) {
+            Ok(connection) => connection,
+            Err(e) => {
+                self.emit(|handler| {
+                    handler.handle_connection_checkout_failed_event(ConnectionCheckOutFailedEvent {
+                        address: self.address.clone(),
+                        reason: ConnectionCheckOutFailedReason::ConnectionError,
+                    })
+                });
+                return Err(e);
+            }
+        };
+        connection.generation = current_generation;
+
+        self.emit(|handler| {
+            handler.handle_connection_created_event(ConnectionCreatedEvent {
+                address: self.address.clone(),
+                connection_id: connection.id,
+            })
+        });
+        self.emit(|handler| {
+            handler.handle_connection_ready_event(ConnectionReadyEvent {
+                address: self.address.clone(),
+                connection_id: connection.id,
+            })
+        });
+        self.emit(|handler| {
+            handler.handle_connection_checked_out_event(ConnectionCheckedOutEvent {
+                address: self.address.clone(),
+                connection_id: connection.id,
+            })
+        });
+
+        Ok(connection)
+    }
+
+    /// Returns a connection to the pool so it can be reused, provided it is still from the
+    /// current generation.
+    pub(crate) fn check_in(&self, mut connection: Connection) {
+        self.emit(|handler| {
+            handler.handle_connection_checked_in_event(ConnectionCheckedInEvent {
+                address: self.address.clone(),
+                connection_id: connection.id,
+            })
+        });
+
+        if connection.generation == self.generation.load(Ordering::SeqCst) {
+            connection.last_used_at = Instant::now();
+            self.connections.lock().unwrap().push(connection);
+        } else {
+            self.emit(|handler| {
+                handler.handle_connection_closed_event(ConnectionClosedEvent {
+                    address: self.address.clone(),
+                    connection_id: connection.id,
+                    reason: ConnectionClosedReason::Stale,
+                })
+            });
+        }
+    }
+
+    /// Clears the pool: drops all idle connections and bumps the generation so that any
+    /// connections currently checked out are discarded on check-in rather than reused.
+    pub(crate) fn clear(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        self.connections.lock().unwrap().clear();
+
+        self.emit(|handler| {
+            handler.handle_pool_cleared_event(PoolClearedEvent {
+                address: self.address.clone(),
+            })
+        });
+    }
+}
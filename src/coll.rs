@@ -0,0 +1,247 @@
+//! Defines the `Collection` type, a handle to a single collection within a database.
+
+use crate::bson::{Bson, Document};
+use crate::client::Client;
+use crate::concern::WriteConcern;
+use crate::cursor::Cursor;
+use crate::error::Result;
+use crate::operation::{Delete, Find, FindAndModify, FindAndModifyAction, Insert, Update};
+use crate::results::{DeleteResult, InsertManyResult, InsertOneResult, UpdateResult};
+
+/// The namespace of a collection: the database and collection name that together identify it
+/// within a deployment.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Namespace {
+    /// The name of the database containing the collection.
+    pub db: String,
+
+    /// The name of the collection.
+    pub coll: String,
+}
+
+impl std::fmt::Display for Namespace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.db, self.coll)
+    }
+}
+
+options_struct! {
+    /// Options for `Collection::insert_one`.
+    pub struct InsertOneOptions {
+        /// A write concern for this insert. `Collection::insert_one` does not send it to the
+        /// server, so the insert runs under the server's default write concern.
+        pub write_concern: Option<WriteConcern>,
+    }
+}
+
+options_struct! {
+    /// Options for `Collection::insert_many`.
+    pub struct InsertManyOptions {
+        /// Whether the server should stop applying inserts after the first error. Defaults to
+        /// `true`.
+        pub ordered: Option<bool>,
+
+        /// A write concern for this insert. `Collection::insert_many` does not send it to the
+        /// server, so the insert runs under the server's default write concern.
+        pub write_concern: Option<WriteConcern>,
+    }
+}
+
+options_struct! {
+    /// Options for `Collection::update_one` and `Collection::update_many`.
+    pub struct UpdateOptions {
+        /// Whether to insert a new document if none match the filter.
+        pub upsert: Option<bool>,
+
+        /// A write concern for this update. `Collection::update_one` and
+        /// `Collection::update_many` do not send it to the server, so the update runs under the
+        /// server's default write concern.
+        pub write_concern: Option<WriteConcern>,
+    }
+}
+
+options_struct! {
+    /// Options for `Collection::delete_one` and `Collection::delete_many`.
+    pub struct DeleteOptions {
+        /// A write concern for this delete. `Collection::delete_one` and
+        /// `Collection::delete_many` ignore their options, so the delete runs under the server's
+        /// default write concern.
+        pub write_concern: Option<WriteConcern>,
+    }
+}
+
+options_struct! {
+    /// Options for `Collection::find_one_and_update`.
+    pub struct FindOneAndUpdateOptions {
+        /// Whether to insert a new document if none match the filter.
+        /// `Collection::find_one_and_update` ignores its options and never upserts.
+        pub upsert: Option<bool>,
+    }
+}
+
+options_struct! {
+    /// Options for `Collection::find_one_and_delete`.
+    pub struct FindOneAndDeleteOptions {}
+}
+
+options_struct! {
+    /// Options for `Collection::find`.
+    pub struct FindOptions {
+        /// The number of documents the server should return in each batch.
+        pub batch_size: Option<i64>,
+
+        /// A limit on the number of documents to return.
+        pub limit: Option<i64>,
+    }
+}
+
+/// A handle to a specific collection within a database, through which CRUD operations can be
+/// performed.
+#[derive(Clone)]
+pub struct Collection {
+    client: Client,
+    db: String,
+    name: String,
+}
+
+impl Collection {
+    pub(crate) fn new(client: Client, db: String, name: String) -> Self {
+        Collection { client, db, name }
+    }
+
+    /// Returns the name of this collection.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the namespace of this collection.
+    pub fn namespace(&self) -> Namespace {
+        Namespace {
+            db: self.db.clone(),
+            coll: self.name.clone(),
+        }
+    }
+
+    /// Inserts a single document into the collection.
+    pub fn insert_one(
+        &self,
+        document: Document,
+        _options: Option<InsertOneOptions>,
+    ) -> Result<InsertOneResult> {
+        let inserted_id = document.get("_id").cloned().unwrap_or(Bson::Null);
+
+        let mut operation = Insert::new(self.db.clone(), self.name.clone(), vec![document], true);
+        self.client.execute_operation(&mut operation)?;
+
+        Ok(InsertOneResult { inserted_id })
+    }
+
+    /// Inserts multiple documents into the collection.
+    pub fn insert_many(
+        &self,
+        documents: impl IntoIterator<Item = Document>,
+        options: Option<InsertManyOptions>,
+    ) -> Result<InsertManyResult> {
+        let ordered = options.and_then(|options| options.ordered).unwrap_or(true);
+
+        let mut operation = Insert::new(
+            self.db.clone(),
+            self.name.clone(),
+            documents.into_iter().collect(),
+            ordered,
+        );
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Updates a single document matching `filter`.
+    pub fn update_one(
+        &self,
+        filter: Document,
+        update: Document,
+        options: Option<UpdateOptions>,
+    ) -> Result<UpdateResult> {
+        let upsert = options.and_then(|options| options.upsert).unwrap_or(false);
+
+        let mut operation =
+            Update::new(self.db.clone(), self.name.clone(), filter, update, false, upsert);
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Updates every document matching `filter`.
+    pub fn update_many(
+        &self,
+        filter: Document,
+        update: Document,
+        options: Option<UpdateOptions>,
+    ) -> Result<UpdateResult> {
+        let upsert = options.and_then(|options| options.upsert).unwrap_or(false);
+
+        let mut operation =
+            Update::new(self.db.clone(), self.name.clone(), filter, update, true, upsert);
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Deletes a single document matching `filter`.
+    pub fn delete_one(&self, filter: Document, _options: Option<DeleteOptions>) -> Result<DeleteResult> {
+        let mut operation = Delete::new(self.db.clone(), self.name.clone(), filter, 1);
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Deletes every document matching `filter`.
+    pub fn delete_many(&self, filter: Document, _options: Option<DeleteOptions>) -> Result<DeleteResult> {
+        let mut operation = Delete::new(self.db.clone(), self.name.clone(), filter, 0);
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Atomically finds a single document matching `filter` and updates it, returning the
+    /// document as it appeared before the update was applied.
+    pub fn find_one_and_update(
+        &self,
+        filter: Document,
+        update: Document,
+        _options: Option<FindOneAndUpdateOptions>,
+    ) -> Result<Option<Document>> {
+        let mut operation = FindAndModify::new(
+            self.db.clone(),
+            self.name.clone(),
+            filter,
+            FindAndModifyAction::Update(update),
+        );
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Atomically finds a single document matching `filter` and deletes it, returning the
+    /// document that was deleted.
+    pub fn find_one_and_delete(
+        &self,
+        filter: Document,
+        _options: Option<FindOneAndDeleteOptions>,
+    ) -> Result<Option<Document>> {
+        let mut operation = FindAndModify::new(
+            self.db.clone(),
+            self.name.clone(),
+            filter,
+            FindAndModifyAction::Delete,
+        );
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Runs a query against the collection, returning a `Cursor` over the matching documents.
+    pub fn find(&self, filter: Option<Document>, options: Option<FindOptions>) -> Result<Cursor> {
+        let mut operation = Find::new(
+            self.client.clone(),
+            self.db.clone(),
+            self.name.clone(),
+            filter,
+            options,
+        );
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Drops this collection from its database.
+    pub fn drop(&self, _options: Option<crate::db::DropCollectionOptions>) -> Result<()> {
+        self.client
+            .run_raw_command(&self.db, crate::bson::doc! { "drop": self.name.clone() })?;
+        Ok(())
+    }
+}
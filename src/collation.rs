@@ -0,0 +1,16 @@
+//! Collation options, which allow users to specify language-specific rules for string
+//! comparison in queries and indexes.
+
+options_struct! {
+    /// Specifies a collation to use for a query or index.
+    pub struct Collation {
+        /// The ICU locale to use.
+        pub locale: Option<String>,
+
+        /// The level of comparison to perform.
+        pub strength: Option<i32>,
+
+        /// Whether to include case comparison.
+        pub case_level: Option<bool>,
+    }
+}
@@ -0,0 +1,44 @@
+//! Read and write concern types, controlling the durability guarantees of operations.
+
+/// The level of acknowledgment requested from the server for write operations.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Acknowledgment {
+    /// Requests acknowledgment that the write has propagated to the given number of nodes;
+    /// `Nodes(0)` requests no acknowledgment at all.
+    Nodes(i32),
+
+    /// Requests acknowledgment that the write has propagated to a majority of voting nodes.
+    Majority,
+
+    /// Requests acknowledgment from members matching a custom write concern tag set.
+    Tag(String),
+}
+
+impl From<i32> for Acknowledgment {
+    fn from(nodes: i32) -> Self {
+        Acknowledgment::Nodes(nodes)
+    }
+}
+
+options_struct! {
+    /// Specifies the level of acknowledgment requested from the server for write operations.
+    pub struct WriteConcern {
+        /// The acknowledgment level desired.
+        pub w: Option<Acknowledgment>,
+
+        /// Requests that the write operation wait until it has been durably committed to the
+        /// on-disk journal before acknowledging it.
+        pub journal: Option<bool>,
+
+        /// Specifies a time limit, in milliseconds, for the write concern to be satisfied.
+        pub w_timeout: Option<i64>,
+    }
+}
+
+options_struct! {
+    /// Specifies the level of consistency and isolation a read operation requires.
+    pub struct ReadConcern {
+        /// The read concern level, e.g. `"local"`, `"majority"`, or `"linearizable"`.
+        pub level: Option<String>,
+    }
+}
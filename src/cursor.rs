@@ -0,0 +1,66 @@
+//! Defines the `Cursor` type, used to iterate over the documents returned by a query.
+
+use crate::bson::Document;
+use crate::client::Client;
+use crate::operation::GetMore;
+use crate::options::StreamAddress;
+
+/// An iterator over the documents returned by a query, transparently fetching additional
+/// batches from the server as needed.
+pub struct Cursor {
+    client: Client,
+    address: StreamAddress,
+    db: String,
+    coll: String,
+    id: i64,
+    buffer: std::collections::VecDeque<Document>,
+}
+
+impl Cursor {
+    pub(crate) fn new(
+        client: Client,
+        address: StreamAddress,
+        db: String,
+        coll: String,
+        id: i64,
+        initial_batch: Vec<Document>,
+    ) -> Self {
+        Cursor {
+            client,
+            address,
+            db,
+            coll,
+            id,
+            buffer: initial_batch.into(),
+        }
+    }
+
+    /// Fetches the next batch from the server that opened this cursor, via `getMore`.
+    ///
+    /// A `getMore` always targets the exact server that returned the cursor's id, bypassing
+    /// server selection and retry entirely: the cursor does not exist anywhere else, so there is
+    /// no other server to retry against.
+    fn get_more(&mut self) -> crate::error::Result<()> {
+        let mut operation = GetMore::new(self.db.clone(), self.coll.clone(), self.id);
+        let result = self.client.execute_operation_on(&mut operation, &self.address)?;
+
+        self.id = result.cursor_id;
+        self.buffer.extend(result.batch);
+
+        Ok(())
+    }
+}
+
+impl Iterator for Cursor {
+    type Item = crate::error::Result<Document>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && self.id != 0 {
+            if let Err(e) = self.get_more() {
+                return Some(Err(e));
+            }
+        }
+
+        self.buffer.pop_front().map(Ok)
+    }
+}
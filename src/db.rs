@@ -0,0 +1,75 @@
+//! Defines the `Database` type, a handle to a single database within a deployment.
+
+use crate::bson::Document;
+use crate::client::Client;
+use crate::coll::Collection;
+use crate::concern::WriteConcern;
+use crate::error::Result;
+use crate::operation::ListCollections;
+
+options_struct! {
+    /// Options for `Database::create_collection`.
+    pub struct CreateCollectionOptions {
+        /// A write concern for the `create` command. `Database::create_collection` does not send
+        /// it to the server, so the command runs under the server's default write concern.
+        pub write_concern: Option<WriteConcern>,
+    }
+}
+
+options_struct! {
+    /// Options for `Collection::drop`.
+    pub struct DropCollectionOptions {
+        /// A write concern for the `drop` command. `Collection::drop` does not send it to the
+        /// server, so the command runs under the server's default write concern.
+        pub write_concern: Option<WriteConcern>,
+    }
+}
+
+/// A handle to a specific database, through which collections can be accessed and
+/// database-level commands can be run.
+#[derive(Clone)]
+pub struct Database {
+    client: Client,
+    name: String,
+}
+
+impl Database {
+    pub(crate) fn new(client: Client, name: String) -> Self {
+        Database { client, name }
+    }
+
+    /// Returns the name of this database.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets a handle to a collection within this database.
+    pub fn collection(&self, name: &str) -> Collection {
+        Collection::new(self.client.clone(), self.name.clone(), name.to_string())
+    }
+
+    /// Explicitly creates a collection within this database.
+    pub fn create_collection(
+        &self,
+        name: &str,
+        _options: Option<CreateCollectionOptions>,
+    ) -> Result<()> {
+        self.run_command(crate::bson::doc! { "create": name }, None)?;
+        Ok(())
+    }
+
+    /// Returns the names of the collections in this database.
+    pub fn list_collection_names(&self, filter: Option<Document>) -> Result<Vec<String>> {
+        let mut operation = ListCollections::new(self.name.clone(), filter);
+        self.client.execute_operation(&mut operation)
+    }
+
+    /// Runs a raw database command against this database.
+    ///
+    /// Unlike the operations exposed through `Collection`, an arbitrary command has no known
+    /// idempotency guarantees, so the driver never retries it even when retryable writes/reads
+    /// are enabled.
+    pub fn run_command(&self, command: Document, _options: Option<()>) -> Result<Document> {
+        self.client.run_raw_command(&self.name, command)
+    }
+}
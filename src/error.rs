@@ -0,0 +1,294 @@
+//! Contains the `Error` and `ErrorKind` types produced by driver operations, along with the
+//! `Result` alias used throughout the crate's public API.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::bson::Document;
+use crate::bson_util;
+
+/// A type alias for results returned by this crate's operations.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The label the driver and server use to mark an error as one that a retryable write should be
+/// retried for.
+pub const RETRYABLE_WRITE_ERROR: &str = "RetryableWriteError";
+
+/// The label the driver and server use to mark an error as one that aborts an in-progress
+/// transaction rather than being returned to the user.
+pub const TRANSIENT_TRANSACTION_ERROR: &str = "TransientTransactionError";
+
+/// The label the driver assigns to a state-change error that requires the affected server's
+/// connection pool to be cleared. `sdam` treats this label, rather than its own error-code
+/// matching, as the single decision point for whether an error is even pool-clear-eligible; it
+/// still inspects `SHUTDOWN_CODES` separately to decide whether the clear is unconditional or
+/// subject to its own `topologyVersion` staleness check.
+pub const RESET_POOL_ERROR: &str = "ResetPool";
+
+/// The label the server attaches to a command error to indicate that the command was not
+/// actually applied, even partially. A retry that fails with this label carries no information
+/// the first attempt's error didn't already have, so it should never be surfaced in its place.
+pub const NO_WRITES_PERFORMED: &str = "NoWritesPerformed";
+
+/// The error codes, as reported by the server, that indicate a node is no longer the primary, is
+/// in the process of shutting down, or (for the bare socket/network-timeout codes) could not be
+/// reached at all. These are used to assign `RETRYABLE_WRITE_ERROR` (and
+/// `TRANSIENT_TRANSACTION_ERROR`) to command errors from servers older than 4.4, which do not
+/// report `errorLabels` themselves.
+const NOT_MASTER_CODES: &[i32] = &[
+    10107, 13435, 11600, 11602, 189, 91, 7, 6, 9001, 64, 50,
+];
+
+/// The error codes the server uses for "shutdown in progress"-style errors. Unlike a plain "not
+/// writable primary" error, these always warrant clearing the server's pool: there is no usable
+/// connection left to preserve. `sdam` uses this list (exported from here, rather than kept as
+/// its own copy) to tell those errors apart from the rest of `RESET_POOL_ERROR`-labeled ones,
+/// which may just be a stale or duplicate report of a state change it has already reacted to.
+pub(crate) const SHUTDOWN_CODES: &[i32] = &[91, 11600];
+
+/// The error codes the server uses for "not writable primary"/"not primary" errors. Like
+/// `SHUTDOWN_CODES`, these are assigned `RESET_POOL_ERROR`, but `sdam` only clears the pool for
+/// them once it has ruled out a stale or duplicate report.
+const NOT_PRIMARY_CODES: &[i32] = &[10107, 13435, 11602, 13436, 189];
+
+/// An error that occurred during a database or network operation.
+///
+/// In addition to its `ErrorKind`, every `Error` carries an ordered set of string labels: some
+/// assigned by the server (via the `errorLabels` field of a command reply) and some assigned by
+/// the driver itself. Labels are the single mechanism retry logic and SDAM consult to decide how
+/// to react to a failure; callers can inspect them directly with `has_error_label`.
+#[derive(Debug, Clone)]
+pub struct Error(Arc<Inner>);
+
+#[derive(Debug, Clone)]
+struct Inner {
+    kind: ErrorKind,
+    labels: Vec<String>,
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind) -> Self {
+        Error(Arc::new(Inner {
+            kind,
+            labels: Vec::new(),
+        }))
+    }
+
+    /// Adds `label` to this error's set of labels, if it is not already present.
+    pub fn add_label(&mut self, label: impl Into<String>) {
+        let label = label.into();
+        let inner = Arc::make_mut(&mut self.0);
+        if !inner.labels.iter().any(|existing| existing == &label) {
+            inner.labels.push(label);
+        }
+    }
+
+    /// Returns whether this error's label set contains `label`.
+    pub fn has_error_label(&self, label: &str) -> bool {
+        self.0.labels.iter().any(|existing| existing == label)
+    }
+
+    /// Returns this error's labels, in the order they were added.
+    pub fn labels(&self) -> &[String] {
+        &self.0.labels
+    }
+
+    /// Builds an `Error` from a network (I/O) failure, attaching `RETRYABLE_WRITE_ERROR` (since
+    /// the driver always considers network errors retryable) and `TRANSIENT_TRANSACTION_ERROR`
+    /// (since a network failure can never have committed a transaction, so it is always safe to
+    /// abort one in progress because of it).
+    pub(crate) fn from_io_error(error: std::io::Error) -> Self {
+        let mut error = Error::new(ErrorKind::IoError(Arc::new(error)));
+        error.add_label(RETRYABLE_WRITE_ERROR);
+        error.add_label(TRANSIENT_TRANSACTION_ERROR);
+        error
+    }
+
+    /// Builds a `CommandError`-kind `Error` from a command reply, if the reply's `ok` field
+    /// indicates the command failed. Any `errorLabels` the server attached are copied over
+    /// first; if they do not already include `RETRYABLE_WRITE_ERROR` (servers older than 4.4
+    /// send no labels at all) and the error's code is one this driver knows indicates the node
+    /// stepped down or is shutting down, `RETRYABLE_WRITE_ERROR` and `TRANSIENT_TRANSACTION_ERROR`
+    /// are assigned by the driver itself, and `RESET_POOL_ERROR` is assigned whenever the code means the affected server's
+    /// connection pool may need to be cleared, regardless of server version.
+    pub(crate) fn from_command_reply(reply: &Document) -> Option<Self> {
+        if reply.get_f64("ok").unwrap_or(1.0) == 1.0 {
+            return None;
+        }
+
+        let command_error = CommandError {
+            code: reply.get_i32("code").unwrap_or(0),
+            code_name: reply
+                .get_str("codeName")
+                .unwrap_or_default()
+                .to_string(),
+            message: reply.get_str("errmsg").unwrap_or_default().to_string(),
+            topology_version: bson_util::get_topology_version(reply),
+        };
+
+        let mut error = Error::new(ErrorKind::CommandError(command_error));
+
+        for label in bson_util::get_error_labels(reply) {
+            error.add_label(label);
+        }
+
+        if !error.has_error_label(RETRYABLE_WRITE_ERROR) && error.matches_legacy_not_master_code() {
+            error.add_label(RETRYABLE_WRITE_ERROR);
+        }
+
+        if !error.has_error_label(TRANSIENT_TRANSACTION_ERROR) && error.matches_legacy_not_master_code()
+        {
+            error.add_label(TRANSIENT_TRANSACTION_ERROR);
+        }
+
+        if error.requires_pool_reset() {
+            error.add_label(RESET_POOL_ERROR);
+        }
+
+        Some(error)
+    }
+
+    fn matches_legacy_not_master_code(&self) -> bool {
+        matches!(
+            self.as_ref(),
+            ErrorKind::CommandError(command_error) if NOT_MASTER_CODES.contains(&command_error.code)
+        )
+    }
+
+    /// Returns whether this error's code means the affected server's connection pool may need to
+    /// be cleared. `sdam` is the one that decides, from `RESET_POOL_ERROR`, whether a stale
+    /// `topologyVersion` rules that out.
+    fn requires_pool_reset(&self) -> bool {
+        matches!(
+            self.as_ref(),
+            ErrorKind::CommandError(command_error)
+                if SHUTDOWN_CODES.contains(&command_error.code)
+                    || NOT_PRIMARY_CODES.contains(&command_error.code)
+        )
+    }
+
+    /// Returns whether the driver should retry the write operation that produced this error.
+    /// This is now a thin wrapper around `has_error_label`, which is populated at error
+    /// construction time for both server-assigned and driver-assigned labels.
+    pub(crate) fn is_retryable(&self) -> bool {
+        self.has_error_label(RETRYABLE_WRITE_ERROR)
+    }
+}
+
+impl std::ops::Deref for Error {
+    type Target = ErrorKind;
+
+    fn deref(&self) -> &ErrorKind {
+        &self.0.kind
+    }
+}
+
+impl AsRef<ErrorKind> for Error {
+    fn as_ref(&self) -> &ErrorKind {
+        &self.0.kind
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Error::new(kind)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0.kind)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The basic classification of failures that can occur while using this crate.
+#[derive(Debug, Clone)]
+pub enum ErrorKind {
+    /// The server returned an error in response to a command.
+    CommandError(CommandError),
+
+    /// An error occurred while establishing or using a network connection to the server.
+    IoError(Arc<std::io::Error>),
+
+    /// The connection pool for a server was cleared while this operation was in progress.
+    ConnectionPoolClearedError {
+        /// A message describing why the pool was cleared.
+        message: String,
+    },
+
+    /// An invalid argument was passed to a driver method.
+    ArgumentError {
+        /// A message describing the invalid argument.
+        message: String,
+    },
+
+    /// An error occurred while serializing or deserializing BSON.
+    BsonError(String),
+
+    /// A catch-all for errors that do not fall into one of the other categories.
+    Internal {
+        /// A message describing the error.
+        message: String,
+    },
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::CommandError(e) => write!(f, "command failed: {}", e),
+            ErrorKind::IoError(e) => write!(f, "I/O error: {}", e),
+            ErrorKind::ConnectionPoolClearedError { message } => {
+                write!(f, "connection pool was cleared: {}", message)
+            }
+            ErrorKind::ArgumentError { message } => write!(f, "invalid argument: {}", message),
+            ErrorKind::BsonError(message) => write!(f, "BSON error: {}", message),
+            ErrorKind::Internal { message } => write!(f, "internal error: {}", message),
+        }
+    }
+}
+
+/// Error information returned by the server in response to a failed command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandError {
+    /// The numeric error code returned by the server.
+    pub code: i32,
+
+    /// The human-readable name the server associated with `code`, e.g. `"NotMaster"`.
+    pub code_name: String,
+
+    /// The error message returned by the server.
+    pub message: String,
+
+    /// The server's topology version at the time it returned this error, if it sent one.
+    /// `sdam` compares this against what it last knew about the server to decide whether this
+    /// error actually describes a newer state change or just a stale/duplicate report of one it
+    /// has already reacted to.
+    pub topology_version: Option<TopologyVersion>,
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.code_name, self.code, self.message)
+    }
+}
+
+/// A server's self-reported view of its own replica set state, used to tell a fresh state-change
+/// error from a stale or duplicate report of one the driver has already reacted to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopologyVersion {
+    /// An identifier generated when the server process starts, which changes on every restart.
+    pub process_id: crate::bson::ObjectId,
+
+    /// A counter the server increments every time its state changes, scoped to `process_id`.
+    pub counter: i64,
+}
+
+impl TopologyVersion {
+    /// Returns whether `self` describes a strictly newer server state than `other`: either the
+    /// server has restarted (a different `process_id`) or its `counter` has advanced.
+    pub(crate) fn is_newer_than(&self, other: &TopologyVersion) -> bool {
+        self.process_id != other.process_id || self.counter > other.counter
+    }
+}
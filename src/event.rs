@@ -0,0 +1,157 @@
+//! Types for observing the driver's internal behavior, e.g. commands sent to the server and
+//! connection pool lifecycle events.
+
+use crate::options::StreamAddress;
+
+/// A handler for CMAP (Connection Monitoring and Pooling) events, used to observe a `Client`'s
+/// connection pool lifecycle. Every method has a no-op default, so an implementor only needs to
+/// override the events it cares about.
+pub trait CmapEventHandler: Send + Sync {
+    /// A connection pool was created for a server.
+    fn handle_pool_created_event(&self, event: PoolCreatedEvent) {}
+
+    /// A connection pool's connections were all closed and its generation bumped, e.g. because of
+    /// a state-change error reported by its server.
+    fn handle_pool_cleared_event(&self, event: PoolClearedEvent) {}
+
+    /// A connection pool was closed, e.g. because its server was removed from the topology.
+    fn handle_pool_closed_event(&self, event: PoolClosedEvent) {}
+
+    /// A connection was created, but has not yet been marked ready for use.
+    fn handle_connection_created_event(&self, event: ConnectionCreatedEvent) {}
+
+    /// A connection finished being established and is ready to be used.
+    fn handle_connection_ready_event(&self, event: ConnectionReadyEvent) {}
+
+    /// A connection was closed and will no longer be used.
+    fn handle_connection_closed_event(&self, event: ConnectionClosedEvent) {}
+
+    /// A thread began attempting to check out a connection from a pool.
+    fn handle_connection_checkout_started_event(&self, event: ConnectionCheckOutStartedEvent) {}
+
+    /// A thread's attempt to check out a connection from a pool failed.
+    fn handle_connection_checkout_failed_event(&self, event: ConnectionCheckOutFailedEvent) {}
+
+    /// A connection was successfully checked out of a pool.
+    fn handle_connection_checked_out_event(&self, event: ConnectionCheckedOutEvent) {}
+
+    /// A connection was checked back into a pool.
+    fn handle_connection_checked_in_event(&self, event: ConnectionCheckedInEvent) {}
+}
+
+/// Emitted when a connection pool is created for a server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolCreatedEvent {
+    /// The address of the server the pool connects to.
+    pub address: StreamAddress,
+}
+
+/// Emitted when a connection pool is cleared.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolClearedEvent {
+    /// The address of the server the pool connects to.
+    pub address: StreamAddress,
+}
+
+/// Emitted when a connection pool is closed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolClosedEvent {
+    /// The address of the server the pool connects to.
+    pub address: StreamAddress,
+}
+
+/// Emitted when a connection is created, before it is marked ready for use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionCreatedEvent {
+    /// The address of the server the connection connects to.
+    pub address: StreamAddress,
+
+    /// A driver-generated identifier, unique to this connection within its pool.
+    pub connection_id: u32,
+}
+
+/// Emitted when a connection finishes being established and becomes ready to be used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionReadyEvent {
+    /// The address of the server the connection connects to.
+    pub address: StreamAddress,
+
+    /// A driver-generated identifier, unique to this connection within its pool.
+    pub connection_id: u32,
+}
+
+/// Why a connection was closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionClosedReason {
+    /// The connection became stale by predating a pool clear.
+    Stale,
+
+    /// The connection had been idle for longer than `max_idle_time`.
+    Idle,
+
+    /// An error occurred while using the connection.
+    Error,
+
+    /// The pool was closed while the connection was idle in it.
+    PoolClosed,
+}
+
+/// Emitted when a connection is closed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionClosedEvent {
+    /// The address of the server the connection connects to.
+    pub address: StreamAddress,
+
+    /// A driver-generated identifier, unique to this connection within its pool.
+    pub connection_id: u32,
+
+    /// Why the connection was closed.
+    pub reason: ConnectionClosedReason,
+}
+
+/// Emitted when a thread begins attempting to check out a connection from a pool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionCheckOutStartedEvent {
+    /// The address of the server the pool connects to.
+    pub address: StreamAddress,
+}
+
+/// Why a connection check-out attempt failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionCheckOutFailedReason {
+    /// An error occurred while establishing the connection.
+    ConnectionError,
+
+    /// The pool has been closed.
+    PoolClosed,
+}
+
+/// Emitted when a thread's attempt to check out a connection from a pool fails.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionCheckOutFailedEvent {
+    /// The address of the server the pool connects to.
+    pub address: StreamAddress,
+
+    /// Why the check-out attempt failed.
+    pub reason: ConnectionCheckOutFailedReason,
+}
+
+/// Emitted when a connection is successfully checked out of a pool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionCheckedOutEvent {
+    /// The address of the server the connection connects to.
+    pub address: StreamAddress,
+
+    /// A driver-generated identifier, unique to this connection within its pool.
+    pub connection_id: u32,
+}
+
+/// Emitted when a connection is checked back into a pool.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionCheckedInEvent {
+    /// The address of the server the connection connects to.
+    pub address: StreamAddress,
+
+    /// A driver-generated identifier, unique to this connection within its pool.
+    pub connection_id: u32,
+}
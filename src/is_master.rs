@@ -0,0 +1,30 @@
+//! The reply to an `isMaster` command, used by `sdam` to build a `ServerDescription`.
+
+use crate::selection_criteria::ReadPreference;
+
+/// The relevant fields of an `isMaster` command reply.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct IsMasterReply {
+    /// Whether the server reported itself as the primary of its replica set.
+    pub(crate) is_writable_primary: bool,
+
+    /// Whether the server reported itself as a secondary of its replica set.
+    pub(crate) secondary: bool,
+
+    /// The replica set name the server reported, if any.
+    pub(crate) set_name: Option<String>,
+}
+
+impl IsMasterReply {
+    /// Whether a server with this reply is a suitable target for an operation with the given
+    /// read preference.
+    pub(crate) fn satisfies(&self, read_preference: &ReadPreference) -> bool {
+        if read_preference.requires_primary() {
+            self.is_writable_primary
+        } else if let ReadPreference::Secondary { .. } = read_preference {
+            self.secondary
+        } else {
+            self.is_writable_primary || self.secondary
+        }
+    }
+}
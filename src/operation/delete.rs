@@ -0,0 +1,70 @@
+use crate::bson::Document;
+use crate::error::Result;
+use crate::options::StreamAddress;
+use crate::results::DeleteResult;
+use crate::selection_criteria::SelectionCriteria;
+
+use super::{Operation, Retryability};
+
+/// The `delete` command, removing documents matching a filter from a collection.
+pub(crate) struct Delete {
+    db: String,
+    coll: String,
+    filter: Document,
+    limit: i32,
+    selection_criteria: SelectionCriteria,
+}
+
+impl Delete {
+    pub(crate) fn new(db: String, coll: String, filter: Document, limit: i32) -> Self {
+        Delete {
+            db,
+            coll,
+            filter,
+            limit,
+            selection_criteria: SelectionCriteria::default(),
+        }
+    }
+}
+
+impl Operation for Delete {
+    type O = DeleteResult;
+
+    const NAME: &'static str = "delete";
+
+    fn db(&self) -> &str {
+        &self.db
+    }
+
+    fn build(&mut self) -> Result<Document> {
+        Ok(crate::bson::doc! {
+            Self::NAME: self.coll.clone(),
+            "deletes": [
+                {
+                    "q": self.filter.clone(),
+                    "limit": self.limit,
+                }
+            ],
+        })
+    }
+
+    fn handle_response(&self, response: Document, _address: &StreamAddress) -> Result<Self::O> {
+        Ok(DeleteResult {
+            deleted_count: response.get_i64("n").unwrap_or(0),
+        })
+    }
+
+    fn selection_criteria(&self) -> &SelectionCriteria {
+        &self.selection_criteria
+    }
+
+    fn retryability(&self) -> Retryability {
+        // A delete that removes at most one document is retryable; an unbounded multi-delete is
+        // not, for the same reason an unordered multi-write is not.
+        if self.limit == 1 {
+            Retryability::Write
+        } else {
+            Retryability::None
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::bson::{Bson, Document};
+use crate::client::Client;
+use crate::coll::FindOptions;
+use crate::cursor::Cursor;
+use crate::error::{ErrorKind, Result};
+use crate::options::StreamAddress;
+use crate::selection_criteria::SelectionCriteria;
+
+use super::{Operation, Retryability};
+
+/// The `find` command, returning a `Cursor` over the matching documents.
+pub(crate) struct Find {
+    client: Client,
+    db: String,
+    coll: String,
+    filter: Option<Document>,
+    options: Option<FindOptions>,
+    selection_criteria: SelectionCriteria,
+}
+
+impl Find {
+    pub(crate) fn new(
+        client: Client,
+        db: String,
+        coll: String,
+        filter: Option<Document>,
+        options: Option<FindOptions>,
+    ) -> Self {
+        Find {
+            client,
+            db,
+            coll,
+            filter,
+            options,
+            selection_criteria: SelectionCriteria::default(),
+        }
+    }
+}
+
+impl Operation for Find {
+    type O = Cursor;
+
+    const NAME: &'static str = "find";
+
+    fn db(&self) -> &str {
+        &self.db
+    }
+
+    fn build(&mut self) -> Result<Document> {
+        let mut command = crate::bson::doc! {
+            Self::NAME: self.coll.clone(),
+            "filter": self.filter.clone().unwrap_or_default(),
+        };
+
+        if let Some(options) = &self.options {
+            if let Some(batch_size) = options.batch_size {
+                command.insert("batchSize", batch_size);
+            }
+            if let Some(limit) = options.limit {
+                command.insert("limit", limit);
+            }
+        }
+
+        Ok(command)
+    }
+
+    fn handle_response(&self, response: Document, address: &StreamAddress) -> Result<Self::O> {
+        let cursor = response.get_document("cursor").map_err(|_| {
+            ErrorKind::Internal {
+                message: "find reply did not contain a cursor field".to_string(),
+            }
+        })?;
+
+        let first_batch: Vec<Document> = cursor
+            .get_array("firstBatch")
+            .map(|batch| {
+                batch
+                    .iter()
+                    .filter_map(|doc| match doc {
+                        Bson::Document(doc) => Some(doc.clone()),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let id = cursor.get_i64("id").unwrap_or(0);
+
+        Ok(Cursor::new(
+            self.client.clone(),
+            address.clone(),
+            self.db.clone(),
+            self.coll.clone(),
+            id,
+            first_batch,
+        ))
+    }
+
+    fn selection_criteria(&self) -> &SelectionCriteria {
+        &self.selection_criteria
+    }
+
+    fn retryability(&self) -> Retryability {
+        // Only the command that establishes the cursor is retried; a subsequent getMore is not,
+        // since it targets a cursor that only exists on the server that created it.
+        Retryability::Read
+    }
+}
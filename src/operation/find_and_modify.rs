@@ -0,0 +1,85 @@
+use crate::bson::{Bson, Document};
+use crate::error::Result;
+use crate::options::StreamAddress;
+use crate::selection_criteria::SelectionCriteria;
+
+use super::{Operation, Retryability};
+
+/// The action a `findAndModify` command should take on the matched document.
+pub(crate) enum FindAndModifyAction {
+    /// Apply an update document.
+    Update(Document),
+
+    /// Delete the matched document.
+    Delete,
+}
+
+/// The `findAndModify` command, atomically finding and updating or deleting a single document.
+pub(crate) struct FindAndModify {
+    db: String,
+    coll: String,
+    filter: Document,
+    action: FindAndModifyAction,
+    selection_criteria: SelectionCriteria,
+}
+
+impl FindAndModify {
+    pub(crate) fn new(
+        db: String,
+        coll: String,
+        filter: Document,
+        action: FindAndModifyAction,
+    ) -> Self {
+        FindAndModify {
+            db,
+            coll,
+            filter,
+            action,
+            selection_criteria: SelectionCriteria::default(),
+        }
+    }
+}
+
+impl Operation for FindAndModify {
+    type O = Option<Document>;
+
+    const NAME: &'static str = "findAndModify";
+
+    fn db(&self) -> &str {
+        &self.db
+    }
+
+    fn build(&mut self) -> Result<Document> {
+        let mut command = crate::bson::doc! {
+            Self::NAME: self.coll.clone(),
+            "query": self.filter.clone(),
+        };
+
+        match &self.action {
+            FindAndModifyAction::Update(update) => {
+                command.insert("update", update.clone());
+            }
+            FindAndModifyAction::Delete => {
+                command.insert("remove", true);
+            }
+        }
+
+        Ok(command)
+    }
+
+    fn handle_response(&self, response: Document, _address: &StreamAddress) -> Result<Self::O> {
+        Ok(match response.get("value") {
+            Some(Bson::Document(document)) => Some(document.clone()),
+            _ => None,
+        })
+    }
+
+    fn selection_criteria(&self) -> &SelectionCriteria {
+        &self.selection_criteria
+    }
+
+    fn retryability(&self) -> Retryability {
+        // findAndModify always affects at most one document, so it is always retryable.
+        Retryability::Write
+    }
+}
@@ -0,0 +1,85 @@
+use crate::bson::{Bson, Document};
+use crate::error::{ErrorKind, Result};
+use crate::options::StreamAddress;
+use crate::selection_criteria::SelectionCriteria;
+
+use super::{Operation, Retryability};
+
+/// The `getMore` command, fetching the next batch of documents for an already-open cursor.
+///
+/// This is never retried: a cursor only exists on the server that created it, so a retryable
+/// error here means the cursor is gone, not that the same command can be reissued elsewhere.
+pub(crate) struct GetMore {
+    db: String,
+    coll: String,
+    cursor_id: i64,
+    selection_criteria: SelectionCriteria,
+}
+
+impl GetMore {
+    pub(crate) fn new(db: String, coll: String, cursor_id: i64) -> Self {
+        GetMore {
+            db,
+            coll,
+            cursor_id,
+            selection_criteria: SelectionCriteria::default(),
+        }
+    }
+}
+
+/// The next batch of documents for a cursor, along with the cursor's id after the fetch (`0` if
+/// the server has exhausted it).
+pub(crate) struct GetMoreResult {
+    pub(crate) batch: Vec<Document>,
+    pub(crate) cursor_id: i64,
+}
+
+impl Operation for GetMore {
+    type O = GetMoreResult;
+
+    const NAME: &'static str = "getMore";
+
+    fn db(&self) -> &str {
+        &self.db
+    }
+
+    fn build(&mut self) -> Result<Document> {
+        Ok(crate::bson::doc! {
+            Self::NAME: self.cursor_id,
+            "collection": self.coll.clone(),
+        })
+    }
+
+    fn handle_response(&self, response: Document, _address: &StreamAddress) -> Result<Self::O> {
+        let cursor = response.get_document("cursor").map_err(|_| {
+            ErrorKind::Internal {
+                message: "getMore reply did not contain a cursor field".to_string(),
+            }
+        })?;
+
+        let batch = cursor
+            .get_array("nextBatch")
+            .map(|batch| {
+                batch
+                    .iter()
+                    .filter_map(|doc| match doc {
+                        Bson::Document(doc) => Some(doc.clone()),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let cursor_id = cursor.get_i64("id").unwrap_or(0);
+
+        Ok(GetMoreResult { batch, cursor_id })
+    }
+
+    fn selection_criteria(&self) -> &SelectionCriteria {
+        &self.selection_criteria
+    }
+
+    fn retryability(&self) -> Retryability {
+        Retryability::None
+    }
+}
@@ -0,0 +1,85 @@
+use crate::bson::{Bson, Document};
+use crate::error::Result;
+use crate::options::StreamAddress;
+use crate::results::InsertManyResult;
+use crate::selection_criteria::SelectionCriteria;
+
+use super::{Operation, Retryability};
+
+/// The `insert` command, inserting one or more documents into a single collection.
+pub(crate) struct Insert {
+    db: String,
+    coll: String,
+    documents: Vec<Document>,
+    ordered: bool,
+    selection_criteria: SelectionCriteria,
+}
+
+impl Insert {
+    pub(crate) fn new(db: String, coll: String, documents: Vec<Document>, ordered: bool) -> Self {
+        Insert {
+            db,
+            coll,
+            documents,
+            ordered,
+            selection_criteria: SelectionCriteria::default(),
+        }
+    }
+}
+
+impl Operation for Insert {
+    type O = InsertManyResult;
+
+    const NAME: &'static str = "insert";
+
+    fn db(&self) -> &str {
+        &self.db
+    }
+
+    fn build(&mut self) -> Result<Document> {
+        let documents: Vec<Bson> = self
+            .documents
+            .iter()
+            .cloned()
+            .map(Bson::Document)
+            .collect();
+
+        Ok(crate::bson::doc! {
+            Self::NAME: self.coll.clone(),
+            "documents": documents,
+            "ordered": self.ordered,
+        })
+    }
+
+    fn handle_response(&self, _response: Document, _address: &StreamAddress) -> Result<Self::O> {
+        let inserted_ids = self
+            .documents
+            .iter()
+            .enumerate()
+            .map(|(i, document)| {
+                (
+                    i,
+                    document.get("_id").cloned().unwrap_or_else(|| Bson::Null),
+                )
+            })
+            .collect();
+
+        Ok(InsertManyResult { inserted_ids })
+    }
+
+    fn selection_criteria(&self) -> &SelectionCriteria {
+        &self.selection_criteria
+    }
+
+    fn retryability(&self) -> Retryability {
+        // Every document in the command is its own write statement, identified by its position
+        // in `documents` under the command's shared txnNumber; the server dedupes a retry
+        // statement-by-statement. An unordered insert is not retried, like any other unordered
+        // bulk write.
+        if self.ordered {
+            Retryability::Write
+        } else {
+            Retryability::None
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::bson::Document;
+use crate::error::{ErrorKind, Result};
+use crate::options::StreamAddress;
+use crate::selection_criteria::SelectionCriteria;
+
+use super::{Operation, Retryability};
+
+/// The `listCollections` command, returning the names of the collections in a database.
+pub(crate) struct ListCollections {
+    db: String,
+    filter: Option<Document>,
+    selection_criteria: SelectionCriteria,
+}
+
+impl ListCollections {
+    pub(crate) fn new(db: String, filter: Option<Document>) -> Self {
+        ListCollections {
+            db,
+            filter,
+            selection_criteria: SelectionCriteria::default(),
+        }
+    }
+}
+
+impl Operation for ListCollections {
+    type O = Vec<String>;
+
+    const NAME: &'static str = "listCollections";
+
+    fn db(&self) -> &str {
+        &self.db
+    }
+
+    fn build(&mut self) -> Result<Document> {
+        let mut command = crate::bson::doc! {
+            Self::NAME: 1,
+            "nameOnly": true,
+        };
+
+        if let Some(filter) = &self.filter {
+            command.insert("filter", filter.clone());
+        }
+
+        Ok(command)
+    }
+
+    fn handle_response(&self, response: Document, _address: &StreamAddress) -> Result<Self::O> {
+        let cursor = response.get_document("cursor").map_err(|_| {
+            ErrorKind::Internal {
+                message: "listCollections reply did not contain a cursor field".to_string(),
+            }
+        })?;
+
+        let names = cursor
+            .get_array("firstBatch")
+            .map(|batch| {
+                batch
+                    .iter()
+                    .filter_map(|entry| entry.as_document())
+                    .filter_map(|entry| entry.get_str("name").ok())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(names)
+    }
+
+    fn selection_criteria(&self) -> &SelectionCriteria {
+        &self.selection_criteria
+    }
+
+    fn retryability(&self) -> Retryability {
+        // listCollections opens a cursor just like find; it carries no write state, so it is
+        // retryable under the same rules.
+        Retryability::Read
+    }
+}
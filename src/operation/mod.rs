@@ -0,0 +1,77 @@
+//! Defines individual server commands as `Operation` implementations, decoupling how a command
+//! is built and its reply parsed from how it gets executed and retried.
+//!
+//! Retryable reads currently cover `find` (the command that opens a cursor) and
+//! `listCollections`; a cursor's subsequent `getMore` calls are never retried, since a cursor
+//! only exists on the server that created it. `aggregate`, `distinct`, `count`, and
+//! `listDatabases` are not implemented as `Operation`s yet, and `Collection`/`Database` do not
+//! expose them at all; they are deferred rather than silently left non-retryable.
+
+mod delete;
+mod find;
+mod find_and_modify;
+mod get_more;
+mod insert;
+mod list_collections;
+mod update;
+
+pub(crate) use delete::Delete;
+pub(crate) use find::Find;
+pub(crate) use find_and_modify::{FindAndModify, FindAndModifyAction};
+pub(crate) use get_more::{GetMore, GetMoreResult};
+pub(crate) use insert::Insert;
+pub(crate) use list_collections::ListCollections;
+pub(crate) use update::Update;
+
+use crate::bson::Document;
+use crate::error::Result;
+use crate::options::StreamAddress;
+use crate::selection_criteria::SelectionCriteria;
+
+/// Whether an operation may be retried once after a retryable error, and which `ClientOptions`
+/// flag gates that retry. Reads and writes are retried under separate options because a server
+/// that predates retryable write support may still support retryable reads, and vice versa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Retryability {
+    /// The operation should never be retried.
+    None,
+
+    /// The operation may be retried once if `retry_reads` is enabled.
+    Read,
+
+    /// The operation may be retried once if `retry_writes` is enabled.
+    Write,
+}
+
+/// A server command, along with the logic needed to build its BSON representation and to parse
+/// a reply document into the operation's output type.
+pub(crate) trait Operation {
+    /// The type this operation's reply is parsed into.
+    type O;
+
+    /// The command name, e.g. `"insert"`, used both as the command's first field and for
+    /// diagnostics.
+    const NAME: &'static str;
+
+    /// The name of the database this operation should run against.
+    fn db(&self) -> &str;
+
+    /// Builds the command document to send to the selected server.
+    fn build(&mut self) -> Result<Document>;
+
+    /// Parses a successful command reply into this operation's output type. `address` is the
+    /// server that produced `response`; only `Find` uses it, to pin the `Cursor` it returns to
+    /// the server that created it, which is where any subsequent `getMore` must go.
+    fn handle_response(&self, response: Document, address: &StreamAddress) -> Result<Self::O>;
+
+    /// The criteria to use when selecting a server to run this operation against.
+    fn selection_criteria(&self) -> &SelectionCriteria;
+
+    /// Whether the driver is allowed to retry this operation once if its first attempt fails
+    /// with a retryable error, and which `ClientOptions` flag gates that. Operations opt into
+    /// this individually; unordered/multi bulk writes and any operation using an explicit
+    /// transaction are never retryable.
+    fn retryability(&self) -> Retryability {
+        Retryability::None
+    }
+}
@@ -0,0 +1,91 @@
+use crate::bson::Document;
+use crate::error::Result;
+use crate::options::StreamAddress;
+use crate::results::UpdateResult;
+use crate::selection_criteria::SelectionCriteria;
+
+use super::{Operation, Retryability};
+
+/// The `update` command, applying a single update statement to a collection.
+pub(crate) struct Update {
+    db: String,
+    coll: String,
+    filter: Document,
+    update: Document,
+    multi: bool,
+    upsert: bool,
+    selection_criteria: SelectionCriteria,
+}
+
+impl Update {
+    pub(crate) fn new(
+        db: String,
+        coll: String,
+        filter: Document,
+        update: Document,
+        multi: bool,
+        upsert: bool,
+    ) -> Self {
+        Update {
+            db,
+            coll,
+            filter,
+            update,
+            multi,
+            upsert,
+            selection_criteria: SelectionCriteria::default(),
+        }
+    }
+}
+
+impl Operation for Update {
+    type O = UpdateResult;
+
+    const NAME: &'static str = "update";
+
+    fn db(&self) -> &str {
+        &self.db
+    }
+
+    fn build(&mut self) -> Result<Document> {
+        Ok(crate::bson::doc! {
+            Self::NAME: self.coll.clone(),
+            "updates": [
+                {
+                    "q": self.filter.clone(),
+                    "u": self.update.clone(),
+                    "multi": self.multi,
+                    "upsert": self.upsert,
+                }
+            ],
+        })
+    }
+
+    fn handle_response(&self, response: Document, _address: &StreamAddress) -> Result<Self::O> {
+        Ok(UpdateResult {
+            matched_count: response.get_i64("n").unwrap_or(0),
+            modified_count: response.get_i64("nModified").unwrap_or(0),
+            upserted_id: response
+                .get_array("upserted")
+                .ok()
+                .and_then(|upserted| upserted.first())
+                .and_then(|entry| entry.as_document())
+                .and_then(|entry| entry.get("_id"))
+                .cloned(),
+        })
+    }
+
+    fn selection_criteria(&self) -> &SelectionCriteria {
+        &self.selection_criteria
+    }
+
+    fn retryability(&self) -> Retryability {
+        // A multi-document update cannot be safely retried: the server may have already applied
+        // the update to some documents before the retryable error occurred.
+        if self.multi {
+            Retryability::None
+        } else {
+            Retryability::Write
+        }
+    }
+}
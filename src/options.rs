@@ -0,0 +1,185 @@
+//! Options types for configuring a `Client` and the operations performed through it.
+//!
+//! Most options types in this crate (and the ones downstream modules define for themselves)
+//! follow the same shape: a plain struct of `Option<T>` fields that doubles as its own builder,
+//! with a value-setting method per field that consumes and returns `self`. The `options_struct!`
+//! macro below generates that boilerplate; it is exported via `#[macro_use]` on this module so
+//! that every module declared after it in `lib.rs` can use it without an explicit import.
+
+/// Declares an options struct whose fields are all `Option<T>`, along with a `builder()`
+/// constructor, a consuming setter per field, and a no-op `build()` that returns `self`.
+macro_rules! options_struct {
+    (
+        $(#[$struct_meta:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                pub $field:ident: Option<$ty:ty>,
+            )*
+        }
+    ) => {
+        $(#[$struct_meta])*
+        #[derive(Clone, Debug, Default, PartialEq)]
+        pub struct $name {
+            $(
+                $(#[$field_meta])*
+                pub $field: Option<$ty>,
+            )*
+        }
+
+        impl $name {
+            /// Returns a default-valued instance of this type, which can be used as a builder by
+            /// chaining the desired setter methods and finishing with `build()`.
+            pub fn builder() -> Self {
+                Self::default()
+            }
+
+            $(
+                $(#[$field_meta])*
+                pub fn $field(mut self, value: impl Into<$ty>) -> Self {
+                    self.$field = Some(value.into());
+                    self
+                }
+            )*
+
+            /// Finalizes the builder. Provided for symmetry with other driver APIs; this type is
+            /// usable directly without calling `build()`.
+            pub fn build(self) -> Self {
+                self
+            }
+        }
+    };
+}
+
+/// The hostname and port of a server to connect to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StreamAddress {
+    /// The hostname of the server.
+    pub hostname: String,
+
+    /// The port the server is listening on. Defaults to 27017 if not specified.
+    pub port: Option<u16>,
+}
+
+impl std::fmt::Display for StreamAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.hostname, self.port.unwrap_or(27017))
+    }
+}
+
+/// Configures the behavior of a `Client`.
+#[derive(Clone, Default)]
+pub struct ClientOptions {
+    /// The initial list of seed hosts to use when discovering the topology.
+    pub hosts: Vec<StreamAddress>,
+
+    /// The name of the application using this client, sent to the server for logging purposes.
+    pub app_name: Option<String>,
+
+    /// The name of the replica set the driver should connect to, if any.
+    pub repl_set_name: Option<String>,
+
+    /// Whether the driver should retry supported write operations once if they fail due to a
+    /// retryable error. Defaults to `true`.
+    pub retry_writes: Option<bool>,
+
+    /// Whether the driver should retry supported read operations once if they fail due to a
+    /// retryable error. Defaults to `true`.
+    pub retry_reads: Option<bool>,
+
+    /// A handler for CMAP events emitted by this client's connection pools.
+    pub cmap_event_handler: Option<std::sync::Arc<dyn crate::event::CmapEventHandler>>,
+
+    /// The minimum number of connections each of this client's connection pools should maintain
+    /// in the background, opening new ones as needed to make up the difference. Defaults to `0`.
+    pub min_pool_size: Option<u32>,
+
+    /// How long a connection may sit idle in a pool before it is closed and removed. Unset means
+    /// connections are never closed for being idle.
+    pub max_idle_time: Option<std::time::Duration>,
+}
+
+impl ClientOptions {
+    /// Returns a default-valued instance of `ClientOptions`, used as a builder.
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Sets the seed list of hosts.
+    pub fn hosts(mut self, hosts: Vec<StreamAddress>) -> Self {
+        self.hosts = hosts;
+        self
+    }
+
+    /// Sets the application name.
+    pub fn app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = Some(app_name.into());
+        self
+    }
+
+    /// Sets the replica set name.
+    pub fn repl_set_name(mut self, repl_set_name: impl Into<String>) -> Self {
+        self.repl_set_name = Some(repl_set_name.into());
+        self
+    }
+
+    /// Sets whether retryable writes are enabled.
+    pub fn retry_writes(mut self, retry_writes: bool) -> Self {
+        self.retry_writes = Some(retry_writes);
+        self
+    }
+
+    /// Returns whether retryable writes are enabled, defaulting to `true` if unset.
+    pub(crate) fn retry_writes_enabled(&self) -> bool {
+        self.retry_writes.unwrap_or(true)
+    }
+
+    /// Sets whether retryable reads are enabled.
+    pub fn retry_reads(mut self, retry_reads: bool) -> Self {
+        self.retry_reads = Some(retry_reads);
+        self
+    }
+
+    /// Returns whether retryable reads are enabled, defaulting to `true` if unset.
+    pub(crate) fn retry_reads_enabled(&self) -> bool {
+        self.retry_reads.unwrap_or(true)
+    }
+
+    /// Sets the handler that will be notified of this client's CMAP events.
+    pub fn cmap_event_handler(
+        mut self,
+        handler: std::sync::Arc<dyn crate::event::CmapEventHandler>,
+    ) -> Self {
+        self.cmap_event_handler = Some(handler);
+        self
+    }
+
+    /// Sets the minimum number of connections each connection pool should maintain.
+    pub fn min_pool_size(mut self, min_pool_size: u32) -> Self {
+        self.min_pool_size = Some(min_pool_size);
+        self
+    }
+
+    /// Sets how long a connection may sit idle in a pool before it is closed and removed.
+    pub fn max_idle_time(mut self, max_idle_time: std::time::Duration) -> Self {
+        self.max_idle_time = Some(max_idle_time);
+        self
+    }
+
+    /// Finalizes the builder.
+    pub fn build(self) -> Self {
+        self
+    }
+}
+
+// The options and supporting types that the rest of the crate defines alongside the code that
+// uses them (e.g. `FindOptions` next to `Collection::find`) are re-exported here so that callers
+// have a single `options` module to import from, matching the rest of this crate's public API.
+pub use crate::collation::Collation;
+pub use crate::coll::{
+    DeleteOptions, FindOneAndDeleteOptions, FindOneAndUpdateOptions, FindOptions,
+    InsertManyOptions, InsertOneOptions, UpdateOptions,
+};
+pub use crate::concern::{Acknowledgment, ReadConcern, WriteConcern};
+pub use crate::db::{CreateCollectionOptions, DropCollectionOptions};
+pub use crate::selection_criteria::{ReadPreference, SelectionCriteria};
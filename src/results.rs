@@ -0,0 +1,41 @@
+//! Result types returned by the various `Collection` operations.
+
+use crate::bson::Bson;
+
+/// The result of an `insert_one` operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertOneResult {
+    /// The `_id` of the document that was inserted, taken from the input document. This is
+    /// `Bson::Null` if the input document had no `_id`: the driver does not generate one, and the
+    /// `_id` the server assigns is not reported.
+    pub inserted_id: Bson,
+}
+
+/// The result of an `insert_many` operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertManyResult {
+    /// The `_id` values of the documents that were inserted, keyed by their index in the
+    /// original input. An input document with no `_id` maps to `Bson::Null`: the driver does not
+    /// generate one, and the `_id` the server assigns is not reported.
+    pub inserted_ids: std::collections::HashMap<usize, Bson>,
+}
+
+/// The result of an `update_one` or `update_many` operation.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UpdateResult {
+    /// The number of documents that matched the filter.
+    pub matched_count: i64,
+
+    /// The number of documents that were modified.
+    pub modified_count: i64,
+
+    /// The `_id` of the document that was upserted, if any.
+    pub upserted_id: Option<Bson>,
+}
+
+/// The result of a `delete_one` or `delete_many` operation.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DeleteResult {
+    /// The number of documents that were deleted.
+    pub deleted_count: i64,
+}
@@ -0,0 +1,174 @@
+//! Server Discovery and Monitoring (SDAM): tracks what is currently known about each server in a
+//! deployment and selects a server to run a given operation against.
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use crate::error::{Error, ErrorKind, Result, TopologyVersion, RESET_POOL_ERROR, SHUTDOWN_CODES};
+use crate::is_master::IsMasterReply;
+use crate::options::{ClientOptions, StreamAddress};
+use crate::selection_criteria::{ReadPreference, SelectionCriteria};
+
+/// What the driver currently believes about a single server.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ServerDescription {
+    pub(crate) address: StreamAddress,
+    reply: Option<IsMasterReply>,
+    topology_version: Option<TopologyVersion>,
+}
+
+impl ServerDescription {
+    fn new(address: StreamAddress) -> Self {
+        ServerDescription {
+            address,
+            // No monitoring handshake has been implemented yet, so every configured server is
+            // optimistically treated as a usable primary until proven otherwise.
+            reply: Some(IsMasterReply {
+                is_writable_primary: true,
+                ..Default::default()
+            }),
+            topology_version: None,
+        }
+    }
+
+    fn is_available(&self) -> bool {
+        self.reply.is_some()
+    }
+}
+
+/// Tracks the state of every server in a deployment and answers server selection requests.
+#[derive(Clone)]
+pub(crate) struct Topology {
+    inner: Arc<RwLock<HashMap<StreamAddress, ServerDescription>>>,
+}
+
+impl Topology {
+    pub(crate) fn new(options: &ClientOptions) -> Self {
+        let servers = options
+            .hosts
+            .iter()
+            .cloned()
+            .map(|address| (address.clone(), ServerDescription::new(address)))
+            .collect();
+
+        Topology {
+            inner: Arc::new(RwLock::new(servers)),
+        }
+    }
+
+    /// Selects a server address suitable for the given selection criteria.
+    pub(crate) fn select_server(&self, criteria: &SelectionCriteria) -> Result<StreamAddress> {
+        let SelectionCriteria::ReadPreference(read_preference) = criteria;
+
+        let servers = self.inner.read().unwrap();
+
+        let candidates: Vec<(&StreamAddress, &IsMasterReply)> = servers
+            .values()
+            .filter(|server| server.is_available())
+            .filter_map(|server| server.reply.as_ref().map(|reply| (&server.address, reply)))
+            .filter(|(_, reply)| reply.satisfies(read_preference))
+            .collect();
+
+        // The "preferred" read preferences accept either kind of server, but pick the preferred
+        // kind whenever one is available.
+        let preferred = candidates.iter().find(|(_, reply)| match read_preference {
+            ReadPreference::PrimaryPreferred { .. } => reply.is_writable_primary,
+            ReadPreference::SecondaryPreferred { .. } => reply.secondary,
+            _ => false,
+        });
+
+        preferred
+            .or_else(|| candidates.first())
+            .map(|(address, _)| (*address).clone())
+            .ok_or_else(|| {
+                ErrorKind::Internal {
+                    message: "no server available matching the selection criteria".to_string(),
+                }
+                .into()
+            })
+    }
+
+    /// Selects a different writable server than `excluding`, for use when retrying a write after
+    /// a retryable error. If no other writable server is known, falls back to ordinary primary
+    /// selection, which may return `excluding` itself, or an error if it is unavailable too.
+    pub(crate) fn select_different_writable_server(
+        &self,
+        excluding: &StreamAddress,
+    ) -> Result<StreamAddress> {
+        let servers = self.inner.read().unwrap();
+
+        let other = servers
+            .values()
+            .find(|server| {
+                &server.address != excluding
+                    && server.is_available()
+                    && server
+                        .reply
+                        .as_ref()
+                        .map(|reply| reply.is_writable_primary)
+                        .unwrap_or(false)
+            })
+            .map(|server| server.address.clone());
+
+        drop(servers);
+
+        match other {
+            Some(address) => Ok(address),
+            None => self.select_server(&SelectionCriteria::default()),
+        }
+    }
+
+    /// Reacts to an error returned by `address`, updating the driver's view of that server and
+    /// returning whether the caller should clear that server's connection pool.
+    ///
+    /// A "shutdown in progress" error always means the pool should be cleared: there is no
+    /// connection worth keeping around. A "not writable primary" error, on the other hand, may
+    /// just be a stale or duplicate report of a state change the driver has already reacted to,
+    /// so it only counts if the reply's `topologyVersion` is newer than the one last seen from
+    /// this server. This driver does not run a monitoring handshake, so a server it has never
+    /// gotten a `topologyVersion` from has no baseline to compare against; rather than treat
+    /// that absence the same as "definitely new" (which would clear the pool on every first
+    /// not-primary error a still-believed-primary server ever reports), a server that was
+    /// otherwise still considered available is given the benefit of the doubt and its reported
+    /// version is simply recorded as the new baseline. A server with no `topologyVersion` at all
+    /// (pre-4.2) has no such baseline to establish, so it is always treated as a fresh state
+    /// change.
+    pub(crate) fn handle_error(&self, address: &StreamAddress, error: &Error) -> bool {
+        // `RESET_POOL_ERROR` is the single decision point for whether this error is even a
+        // candidate for clearing the pool, replacing the error-code matching this used to do
+        // directly; `error.rs` is what assigns the label.
+        if !error.has_error_label(RESET_POOL_ERROR) {
+            return false;
+        }
+
+        let command_error = match error.as_ref() {
+            ErrorKind::CommandError(command_error) => command_error,
+            _ => return false,
+        };
+
+        let mut servers = self.inner.write().unwrap();
+        let server = match servers.get_mut(address) {
+            Some(server) => server,
+            None => return false,
+        };
+
+        // A "shutdown in progress" error always warrants clearing the pool unconditionally; any
+        // other `RESET_POOL_ERROR` is a "not writable primary"-style error, which may just be a
+        // stale or duplicate report of a state change already reacted to.
+        if !SHUTDOWN_CODES.contains(&command_error.code) {
+            match (&command_error.topology_version, &server.topology_version) {
+                (Some(incoming), Some(known)) if !incoming.is_newer_than(known) => return false,
+                (Some(incoming), None) if server.is_available() => {
+                    server.topology_version = Some(incoming.clone());
+                    return false;
+                }
+                _ => {}
+            }
+        }
+
+        server.topology_version = command_error.topology_version.clone();
+        server.reply = None;
+
+        true
+    }
+}
@@ -0,0 +1,58 @@
+//! Types for choosing which server in a topology an operation should run against.
+
+use std::time::Duration;
+
+/// Specifies which server(s) an operation should be allowed to run on.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SelectionCriteria {
+    /// Select a server according to a read preference.
+    ReadPreference(ReadPreference),
+}
+
+/// The read preference a read operation should use when selecting a server.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReadPreference {
+    /// Only read from the primary.
+    Primary,
+
+    /// Prefer the primary, falling back to a secondary if none is available.
+    PrimaryPreferred {
+        /// How stale a secondary is allowed to be before it is excluded from selection. Server
+        /// selection does not track staleness, so this value has no effect.
+        max_staleness: Option<Duration>,
+    },
+
+    /// Only read from a secondary.
+    Secondary {
+        /// How stale a secondary is allowed to be before it is excluded from selection. Server
+        /// selection does not track staleness, so this value has no effect.
+        max_staleness: Option<Duration>,
+    },
+
+    /// Prefer a secondary, falling back to the primary if none is available.
+    SecondaryPreferred {
+        /// How stale a secondary is allowed to be before it is excluded from selection. Server
+        /// selection does not track staleness, so this value has no effect.
+        max_staleness: Option<Duration>,
+    },
+
+    /// Select the nearest server, regardless of its type.
+    Nearest {
+        /// How stale a secondary is allowed to be before it is excluded from selection. Server
+        /// selection does not track staleness, so this value has no effect.
+        max_staleness: Option<Duration>,
+    },
+}
+
+impl ReadPreference {
+    /// Whether this read preference requires the selected server to be writable.
+    pub(crate) fn requires_primary(&self) -> bool {
+        matches!(self, ReadPreference::Primary)
+    }
+}
+
+impl Default for SelectionCriteria {
+    fn default() -> Self {
+        SelectionCriteria::ReadPreference(ReadPreference::Primary)
+    }
+}
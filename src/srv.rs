@@ -0,0 +1,5 @@
+//! DNS seedlist discovery (`mongodb+srv://` connection strings).
+
+// Resolving `mongodb+srv://` connection strings requires a DNS client capable of SRV and TXT
+// record lookups, which this crate does not yet depend on. `ClientOptions` must currently be
+// built up directly, or from a standard (non-SRV) connection string once that parser exists.
@@ -0,0 +1,9 @@
+//! Miscellaneous helpers shared across the driver's modules.
+
+use std::time::{Duration, Instant};
+
+/// Returns the duration elapsed since `instant`, saturating at zero rather than panicking if the
+/// clock has not advanced (or appears to have gone backwards).
+pub fn duration_since(instant: Instant) -> Duration {
+    Instant::now().saturating_duration_since(instant)
+}
@@ -6,10 +6,10 @@ use lazy_static::lazy_static;
 use mongodb::{
     error::{CommandError, ErrorKind},
     options::{
-        Acknowledgment, CreateCollectionOptions, DropCollectionOptions, FindOptions,
-        InsertManyOptions, WriteConcern,
+        Acknowledgment, ClientOptions, CreateCollectionOptions, DropCollectionOptions,
+        FindOptions, InsertManyOptions, StreamAddress, WriteConcern,
     },
-    Collection, Database,
+    Client, Collection, Database,
 };
 
 use crate::util::EventClient;
@@ -20,8 +20,19 @@ lazy_static! {
 }
 
 fn run_test(name: &str, test: impl Fn(EventClient, Database, Collection)) {
-    // TODO RUST-51: Disable retryable writes once they're implemented.
-    let client = EventClient::new();
+    // Retryable writes must be disabled here: several of the tests below fail a single write
+    // attempt via a one-shot failpoint and assert that the resulting error is returned as-is,
+    // which only holds if the driver does not transparently retry it.
+    run_test_with_retry_writes(name, false, test)
+}
+
+fn run_test_with_retry_writes(
+    name: &str,
+    retry_writes: bool,
+    test: impl Fn(EventClient, Database, Collection),
+) {
+    let client =
+        EventClient::with_options(ClientOptions::builder().retry_writes(retry_writes).build());
 
     if client.options.repl_set_name.is_none() {
         return;
@@ -250,3 +261,104 @@ fn interrupted_at_shutdown() {
             .expect("insert should have succeeded");
     })
 }
+
+#[function_name::named]
+#[test]
+fn retryable_read_succeeds_after_not_master_error() {
+    run_test(function_name!(), |client, _, coll| {
+        let _lock = STEPDOWN_TEST_MUTEX.lock();
+
+        coll.insert_one(doc! { "test": 1 }, None)
+            .expect("insert should have succeeded");
+
+        client
+            .database("admin")
+            .run_command(
+                doc! {
+                    "configureFailPoint": "failCommand",
+                    "mode": { "times": 1 },
+                    "data": {
+                        "failCommands": ["find"],
+                        "errorCode": 10107
+                    }
+                },
+                None,
+            )
+            .unwrap();
+
+        // The first attempt should fail with a retryable "not writable primary" error, but the
+        // driver should retry it once automatically and return the matching document.
+        let mut cursor = coll.find(Some(doc! { "test": 1 }), None).unwrap();
+        assert!(cursor.next().unwrap().is_ok());
+    });
+}
+
+#[function_name::named]
+#[test]
+fn retry_write_no_writes_performed_keeps_original_error() {
+    // Unlike the rest of this file, this test needs retryable writes *enabled*: it is the retry
+    // attempt's own NoWritesPerformed error that this request's preservation logic has to see
+    // past, so without a retry there is nothing for it to exercise.
+    run_test_with_retry_writes(function_name!(), true, |client, _, coll| {
+        // A single `failCommand` fail point can't express two different errors across the
+        // driver's two attempts: the second `configureFailPoint` call below would just replace
+        // the first before `insert_one` ever ran, so the retry would never see a distinct error
+        // at all. Arming one fail point per host instead gives each attempt its own error no
+        // matter which host the driver tries first.
+        if client.options.hosts.len() < 2 {
+            return;
+        }
+
+        let _lock = STEPDOWN_TEST_MUTEX.lock();
+
+        let direct_client = |address: &StreamAddress| {
+            Client::with_options(ClientOptions::builder().hosts(vec![address.clone()]).build())
+                .unwrap()
+        };
+
+        // One host is armed with a retryable "not writable primary" error; the other, with an
+        // unrelated retryable error that carries NoWritesPerformed, meaning a retry that lands
+        // there never actually ran the write. Whichever host the first attempt happens to hit,
+        // its error carries no NoWritesPerformed label, so the surfaced result is always that
+        // host's own error -- either directly (if it's the retry) or preserved in place of the
+        // other host's NoWritesPerformed error (if it's the original).
+        direct_client(&client.options.hosts[0])
+            .database("admin")
+            .run_command(
+                doc! {
+                    "configureFailPoint": "failCommand",
+                    "mode": { "times": 1 },
+                    "data": {
+                        "failCommands": ["insert"],
+                        "errorCode": 10107
+                    }
+                },
+                None,
+            )
+            .unwrap();
+
+        direct_client(&client.options.hosts[1])
+            .database("admin")
+            .run_command(
+                doc! {
+                    "configureFailPoint": "failCommand",
+                    "mode": { "times": 1 },
+                    "data": {
+                        "failCommands": ["insert"],
+                        "errorCode": 91,
+                        "errorLabels": ["NoWritesPerformed"]
+                    }
+                },
+                None,
+            )
+            .unwrap();
+
+        let result = coll.insert_one(doc! { "test": 1 }, None);
+        assert_matches!(
+            result.as_ref().map_err(|e| e.as_ref()),
+            Err(ErrorKind::CommandError(CommandError { code: 10107, .. })),
+            "the original error should have been returned instead of the NoWritesPerformed retry \
+             error"
+        );
+    })
+}